@@ -161,6 +161,28 @@ impl AttrSigInfo {
         result
     }
 
+    /// Create code that validates input arguments marked with `#[validate]` using the
+    /// `near_sdk::Validate` trait.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// if let Err(err) = near_sdk::Validate::validate(&arg0) {
+    ///     near_sdk::env::panic_str(&format!("Invalid argument `arg0`: {}", err));
+    /// }
+    /// ```
+    pub fn input_validation(&self) -> TokenStream2 {
+        self.input_args().filter(|arg| arg.is_validated).fold(TokenStream2::new(), |acc, arg| {
+            let ident = &arg.ident;
+            let error_msg = format!("Invalid argument `{}`: {{}}", ident);
+            quote! {
+                #acc
+                if let Err(err) = near_sdk::Validate::validate(&#ident) {
+                    near_sdk::env::panic_str(&format!(#error_msg, err));
+                }
+            }
+        })
+    }
+
     /// Create code that deserializes arguments that were decorated with `#[callback*]`
     pub fn callback_deserialization(&self) -> TokenStream2 {
         self.args
@@ -23,6 +23,14 @@ impl ImplItemMethodInfo {
             arg_struct = attr_signature_info.input_struct(InputStructType::Deserialization);
             let decomposition = attr_signature_info.decomposition_pattern();
             let serializer_invocation = match attr_signature_info.input_serializer {
+                // Empty input is treated as an empty JSON object, since all arguments can be omitted.
+                SerializerType::JSON if attr_signature_info.input_args_are_optional() => quote! {
+                    near_sdk::serde_json::from_slice(
+                        &near_sdk::env::input()
+                            .filter(|input| !input.is_empty())
+                            .unwrap_or_else(|| b"{}".to_vec())
+                    ).expect("Failed to deserialize input from JSON.")
+                },
                 SerializerType::JSON => quote! {
                     near_sdk::serde_json::from_slice(
                         &near_sdk::env::input().expect("Expected input since method has arguments.")
@@ -42,6 +50,7 @@ impl ImplItemMethodInfo {
             arg_parsing = TokenStream2::new();
         };
 
+        let arg_validation = attr_signature_info.input_validation();
         let callback_deser = attr_signature_info.callback_deserialization();
         let callback_vec_deser = attr_signature_info.callback_vec_deserialization();
 
@@ -55,6 +64,7 @@ impl ImplItemMethodInfo {
             method_type,
             is_payable,
             is_private,
+            only_roles,
             is_handles_result,
             ..
         } = attr_signature_info;
@@ -81,12 +91,41 @@ impl ImplItemMethodInfo {
             quote! {}
         };
         let body = if matches!(method_type, &MethodType::Init) {
-            match init_method_wrapper(self, true) {
+            let state_check = quote! {
+                if near_sdk::env::state_exists() {
+                    near_sdk::env::panic_str("The contract has already been initialized");
+                }
+            };
+            match init_method_wrapper(
+                self,
+                state_check,
+                "Init methods must return the contract state",
+            ) {
                 Ok(wrapper) => wrapper,
                 Err(err) => return err.to_compile_error(),
             }
         } else if matches!(method_type, &MethodType::InitIgnoreState) {
-            match init_method_wrapper(self, false) {
+            match init_method_wrapper(
+                self,
+                quote! {},
+                "Init methods must return the contract state",
+            ) {
+                Ok(wrapper) => wrapper,
+                Err(err) => return err.to_compile_error(),
+            }
+        } else if matches!(method_type, &MethodType::Migrate) {
+            // The old state is read as raw bytes because its layout doesn't match the current
+            // contract type.
+            let old_state = &self.attr_signature_info.args[0].ident;
+            let state_read = quote! {
+                let #old_state: Vec<u8> = near_sdk::env::state_read_raw()
+                    .unwrap_or_else(|| near_sdk::env::panic_str("The contract state doesn't exist"));
+            };
+            match init_method_wrapper(
+                self,
+                state_read,
+                "Migrate methods must return the contract state",
+            ) {
                 Ok(wrapper) => wrapper,
                 Err(err) => return err.to_compile_error(),
             }
@@ -96,8 +135,16 @@ impl ImplItemMethodInfo {
             let contract_ser;
             if let Some(receiver) = receiver {
                 let mutability = &receiver.mutability;
+                let role_check = if only_roles.is_empty() {
+                    TokenStream2::new()
+                } else {
+                    quote! {
+                        near_sdk::AssertRole::assert_role(&contract, &[#(#only_roles),*]);
+                    }
+                };
                 contract_deser = quote! {
                     let #mutability contract: #struct_type = near_sdk::env::state_read().unwrap_or_default();
+                    #role_check
                 };
                 method_invocation = quote! {
                     contract.#ident(#arg_list)
@@ -116,6 +163,14 @@ impl ImplItemMethodInfo {
                 };
                 contract_ser = TokenStream2::new();
             }
+            let value_ser = match result_serializer {
+                SerializerType::JSON => quote! {
+                    let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
+                },
+                SerializerType::Borsh => quote! {
+                    let result = near_sdk::borsh::BorshSerialize::try_to_vec(&result).expect("Failed to serialize the return value using Borsh.");
+                },
+            };
             match returns {
                 ReturnType::Default => quote! {
                     #contract_deser
@@ -125,14 +180,6 @@ impl ImplItemMethodInfo {
                 ReturnType::Type(_, return_type)
                     if utils::type_is_result(return_type) && *is_handles_result =>
                 {
-                    let value_ser = match result_serializer {
-                        SerializerType::JSON => quote! {
-                            let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
-                        },
-                        SerializerType::Borsh => quote! {
-                            let result = near_sdk::borsh::BorshSerialize::try_to_vec(&result).expect("Failed to serialize the return value using Borsh.");
-                        },
-                    };
                     quote! {
                         #contract_deser
                         let result = #method_invocation;
@@ -164,15 +211,27 @@ impl ImplItemMethodInfo {
                     )
                     .to_compile_error();
                 }
+                ReturnType::Type(_, return_type)
+                    if utils::type_is_promise_or_value(return_type) =>
+                {
+                    // Only the value is serialized, the promise is returned so that its result
+                    // becomes the result of this method.
+                    quote! {
+                        #contract_deser
+                        let result = #method_invocation;
+                        match result {
+                            near_sdk::PromiseOrValue::Promise(promise) => {
+                                promise.as_return();
+                            }
+                            near_sdk::PromiseOrValue::Value(result) => {
+                                #value_ser
+                                near_sdk::env::value_return(&result);
+                            }
+                        }
+                        #contract_ser
+                    }
+                }
                 ReturnType::Type(_, _) => {
-                    let value_ser = match result_serializer {
-                        SerializerType::JSON => quote! {
-                            let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
-                        },
-                        SerializerType::Borsh => quote! {
-                            let result = near_sdk::borsh::BorshSerialize::try_to_vec(&result).expect("Failed to serialize the return value using Borsh.");
-                        },
-                    };
                     quote! {
                         #contract_deser
                         let result = #method_invocation;
@@ -199,6 +258,7 @@ impl ImplItemMethodInfo {
                 #deposit_check
                 #arg_struct
                 #arg_parsing
+                #arg_validation
                 #callback_deser
                 #callback_vec_deser
                 #body
@@ -265,31 +325,23 @@ impl ImplItemMethodInfo {
     }
 }
 
+/// Generates the body of a method that returns the new contract state, e.g. an init or migrate
+/// method. `preamble` runs before the method is called.
 fn init_method_wrapper(
     method_info: &ImplItemMethodInfo,
-    check_state: bool,
+    preamble: TokenStream2,
+    missing_return_msg: &str,
 ) -> Result<TokenStream2, syn::Error> {
     let ImplItemMethodInfo { attr_signature_info, struct_type, .. } = method_info;
     let arg_list = attr_signature_info.arg_list();
     let AttrSigInfo { ident, returns, is_handles_result, .. } = attr_signature_info;
-    let state_check = if check_state {
-        quote! {
-            if near_sdk::env::state_exists() {
-                near_sdk::env::panic_str("The contract has already been initialized");
-            }
-        }
-    } else {
-        quote! {}
-    };
     match returns {
-        ReturnType::Default => {
-            Err(syn::Error::new(ident.span(), "Init methods must return the contract state"))
-        }
+        ReturnType::Default => Err(syn::Error::new(ident.span(), missing_return_msg)),
         ReturnType::Type(_, return_type)
             if utils::type_is_result(return_type) && *is_handles_result =>
         {
             Ok(quote! {
-                #state_check
+                #preamble
                 let result = #struct_type::#ident(#arg_list);
                 match result {
                     Ok(contract) => near_sdk::env::state_write(&contract),
@@ -302,7 +354,7 @@ fn init_method_wrapper(
             "Method marked with #[handle_result] should return Result<T, E>",
         )),
         ReturnType::Type(_, _) => Ok(quote! {
-            #state_check
+            #preamble
             let contract = #struct_type::#ident(#arg_list);
             near_sdk::env::state_write(&contract);
         }),
@@ -236,6 +236,37 @@ mod tests {
         assert_eq!(expected.to_string(), actual.to_string());
     }
 
+    #[test]
+    fn optional_args_empty_input() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod =
+            syn::parse_str("pub fn method(&self, k: Option<u64>, m: Option<Bar>) { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                struct Input {
+                    k: Option<u64>,
+                    m: Option<Bar>,
+                }
+                let Input { k, m, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input()
+                        .filter(|input| !input.is_empty())
+                        .unwrap_or_else(|| b"{}".to_vec())
+                )
+                .expect("Failed to deserialize input from JSON.");
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(k, m, );
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
     #[test]
     fn args_return_ref() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
@@ -552,6 +583,182 @@ mod tests {
         assert_eq!(expected.to_string(), actual.to_string());
     }
 
+    #[test]
+    fn migrate() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[migrate]
+            pub fn method(old_state: Vec<u8>) -> Self { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                if near_sdk::env::current_account_id() != near_sdk::env::predecessor_account_id() {
+                    near_sdk::env::panic_str("Method method is private");
+                }
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::env::panic_str("Method method doesn't accept deposit");
+                }
+                let old_state: Vec<u8> = near_sdk::env::state_read_raw()
+                    .unwrap_or_else(|| near_sdk::env::panic_str("The contract state doesn't exist"));
+                let contract = Hello::method(old_state, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn migrate_with_input_args() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[migrate]
+            pub fn method(old_state: Vec<u8>, k: u64) -> Self { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type).map(|_| ()).unwrap_err();
+        let expected = "Migrate methods should take the old state bytes as their only argument";
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn migrate_old_state_slice() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[migrate]
+            pub fn method(old_state: &[u8]) -> Self { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                if near_sdk::env::current_account_id() != near_sdk::env::predecessor_account_id() {
+                    near_sdk::env::panic_str("Method method is private");
+                }
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::env::panic_str("Method method doesn't accept deposit");
+                }
+                let old_state: Vec<u8> = near_sdk::env::state_read_raw()
+                    .unwrap_or_else(|| near_sdk::env::panic_str("The contract state doesn't exist"));
+                let contract = Hello::method(&old_state, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn migrate_old_state_wrong_type() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[migrate]
+            pub fn method(old_state: OldHello) -> Self { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type).map(|_| ()).unwrap_err();
+        let expected = "The old state argument of a migrate method should be `Vec<u8>` or `&[u8]`";
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn migrate_old_state_qualified_vec() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[migrate]
+            pub fn method(old_state: std::vec::Vec<u8>) -> Self { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_ok());
+    }
+
+    #[test]
+    fn migrate_with_receiver() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[migrate]
+            pub fn method(&self, old_state: Vec<u8>) -> Self { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type).map(|_| ()).unwrap_err();
+        let expected = "Migrate methods can't have `self` receiver";
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn migrate_and_init() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[migrate]
+            #[init]
+            pub fn method(old_state: Vec<u8>) -> Self { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type.clone()).map(|_| ()).unwrap_err();
+        let expected = "Methods can't be marked with both #[init] and #[migrate]";
+        assert_eq!(expected, actual.to_string());
+
+        let mut method: ImplItemMethod = parse_quote! {
+            #[init]
+            #[migrate]
+            pub fn method(old_state: Vec<u8>) -> Self { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type).map(|_| ()).unwrap_err();
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn migrate_handle_result() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[migrate]
+            #[handle_result]
+            pub fn method(old_state: Vec<u8>) -> Result<Self, &'static str> { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                if near_sdk::env::current_account_id() != near_sdk::env::predecessor_account_id() {
+                    near_sdk::env::panic_str("Method method is private");
+                }
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::env::panic_str("Method method doesn't accept deposit");
+                }
+                let old_state: Vec<u8> = near_sdk::env::state_read_raw()
+                    .unwrap_or_else(|| near_sdk::env::panic_str("The contract state doesn't exist"));
+                let result = Hello::method(old_state, );
+                match result {
+                    Ok(contract) => near_sdk::env::state_write(&contract),
+                    Err(err) => near_sdk::FunctionError::panic(&err)
+                }
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn migrate_handle_result_incorrect_return_type() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[migrate]
+            #[handle_result]
+            pub fn method(old_state: Vec<u8>) -> Self { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            compile_error! {
+                "Method marked with #[handle_result] should return Result<T, E>"
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
     #[test]
     fn init_payable() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
@@ -623,6 +830,220 @@ mod tests {
         assert_eq!(expected.to_string(), actual.to_string());
     }
 
+    #[test]
+    fn only_roles() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[only(roles("admin", "owner"))]
+            pub fn method(&mut self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::env::panic_str("Method method doesn't accept deposit");
+                }
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                near_sdk::AssertRole::assert_role(&contract, &["admin", "owner"]);
+                contract.method();
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn only_roles_without_self() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[only(roles("admin"))]
+            pub fn method() { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type).map(|_| ()).unwrap_err();
+        let expected = "Methods with #[only] attribute must have `self` receiver";
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn only_roles_view() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[only(roles("admin"))]
+            pub fn method(&self) { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type).map(|_| ()).unwrap_err();
+        let expected = "Methods with #[only] attribute must be mutable (not view)";
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn only_unsupported_attribute() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[only(foo("a"))]
+            pub fn method(&mut self) { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type).map(|_| ()).unwrap_err();
+        let expected = "Unsupported only attribute.";
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn only_empty_roles() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[only(roles())]
+            pub fn method(&mut self) { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type).map(|_| ()).unwrap_err();
+        let expected = "At least one role should be specified.";
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn validate_args() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[validate] k: Range, m: u64, #[validate] n: Option<Name>) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::env::panic_str("Method method doesn't accept deposit");
+                }
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                struct Input {
+                    k: Range,
+                    m: u64,
+                    n: Option<Name>,
+                }
+                let Input { k, m, n, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .expect("Failed to deserialize input from JSON.");
+                if let Err(err) = near_sdk::Validate::validate(&k) {
+                    near_sdk::env::panic_str(&format!("Invalid argument `k`: {}", err));
+                }
+                if let Err(err) = near_sdk::Validate::validate(&n) {
+                    near_sdk::env::panic_str(&format!("Invalid argument `n`: {}", err));
+                }
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(k, m, n, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn validate_callback_arg() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[private] pub fn method(&self, #[callback_unwrap] #[validate] x: Range) { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type).map(|_| ()).unwrap_err();
+        let expected = "#[validate] is only supported on arguments read from the input";
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn args_json_return_borsh() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(borsh)]
+            pub fn method(&self, k: u64) -> Option<u64> { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                struct Input {
+                    k: u64,
+                }
+                let Input { k, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .expect("Failed to deserialize input from JSON.");
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = contract.method(k, );
+                let result = near_sdk::borsh::BorshSerialize::try_to_vec(&result)
+                    .expect("Failed to serialize the return value using Borsh.");
+                near_sdk::env::value_return(&result);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn return_promise_or_value() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod =
+            syn::parse_str("pub fn method(&mut self) -> PromiseOrValue<u64> { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::env::panic_str("Method method doesn't accept deposit");
+                }
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = contract.method();
+                match result {
+                    near_sdk::PromiseOrValue::Promise(promise) => {
+                        promise.as_return();
+                    }
+                    near_sdk::PromiseOrValue::Value(result) => {
+                        let result = near_sdk::serde_json::to_vec(&result)
+                            .expect("Failed to serialize the return value using JSON.");
+                        near_sdk::env::value_return(&result);
+                    }
+                }
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn return_other_promise_or_value() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod =
+            syn::parse_str("pub fn method(&self) -> my_mod::PromiseOrValue<u64> { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = contract.method();
+                let result =
+                    near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
     #[test]
     fn callback_args_mixed_serialization() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
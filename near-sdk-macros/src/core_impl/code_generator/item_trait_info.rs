@@ -1,6 +1,6 @@
 use crate::core_impl::info_extractor::ItemTraitInfo;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{format_ident, quote};
 
 impl ItemTraitInfo {
     /// Generate code that wrapps external calls.
@@ -10,14 +10,55 @@ impl ItemTraitInfo {
             result.extend(method.method_wrapper());
         }
         let mod_name = &self.mod_name;
+        // The mock only exists in test builds, so a mock that can't be generated doesn't fail others.
+        let mock_struct = self.mock_struct().unwrap_or_else(|err| {
+            let err = err.to_compile_error();
+            quote! {
+                #[cfg(test)]
+                #err
+            }
+        });
         quote! {
            pub mod #mod_name {
                 use super::*;
                 use near_sdk::{Gas, Balance, AccountId, Promise};
                 #result
+                #mock_struct
             }
         }
     }
+
+    /// Generate a mock of the external contract for unit tests. The mock only holds a settable
+    /// closure for each trait method, which is called when the corresponding mock method is called.
+    /// It is not connected to the generated functions, which still create promises. It is allowed
+    /// to be unused since most contracts never construct it.
+    pub fn mock_struct(&self) -> syn::Result<TokenStream2> {
+        let mock_name = format_ident!("Mock{}", self.original.ident);
+        if let Some(method) =
+            self.methods.iter().find(|method| method.attr_sig_info.ident == "default")
+        {
+            return Err(syn::Error::new(
+                method.attr_sig_info.ident.span(),
+                format!("Method `default` collides with `Default::default` of `{}`", mock_name),
+            ));
+        }
+        let fields = self.methods.iter().map(|method| method.mock_field());
+        let methods = self.methods.iter().map(|method| method.mock_method());
+        Ok(quote! {
+            #[cfg(test)]
+            #[allow(dead_code)]
+            #[derive(Default)]
+            pub struct #mock_name {
+                #(#fields)*
+            }
+
+            #[cfg(test)]
+            #[allow(dead_code)]
+            impl #mock_name {
+                #(#methods)*
+            }
+        })
+    }
 }
 
 // Rustfmt removes comas.
@@ -83,6 +124,23 @@ mod tests {
                         __gas,
                     )
                 }
+                #[cfg(test)]
+                #[allow(dead_code)]
+                #[derive(Default)]
+                pub struct MockExternalCrossContract {
+                    pub merge_sort: Option<Box<dyn Fn(Vec<u8>) -> PromiseOrValue<Vec<u8> > >>,
+                    pub merge: Option<Box<dyn Fn(Vec<u8>, Vec<u8>) -> Vec<u8> >>,
+                }
+                #[cfg(test)]
+                #[allow(dead_code)]
+                impl MockExternalCrossContract {
+                    pub fn merge_sort(&self, arr: Vec<u8>) -> PromiseOrValue<Vec<u8> > {
+                        (self.merge_sort.as_ref().expect("Mock for `merge_sort` is not set"))(arr)
+                    }
+                    pub fn merge(&self, data0: Vec<u8>, data1: Vec<u8>) -> Vec<u8> {
+                        (self.merge.as_ref().expect("Mock for `merge` is not set"))(data0, data1)
+                    }
+                }
             }
         };
         assert_eq!(actual.to_string(), expected.to_string());
@@ -125,8 +183,36 @@ mod tests {
                     __gas,
                 )
             }
+            #[cfg(test)]
+            #[allow(dead_code)]
+            #[derive(Default)]
+            pub struct MockTestExt {
+                pub test: Option<Box<dyn Fn(Vec<String>) -> Vec<String> >>,
+            }
+            #[cfg(test)]
+            #[allow(dead_code)]
+            impl MockTestExt {
+                pub fn test(&self, v: Vec<String>) -> Vec<String> {
+                    (self.test.as_ref().expect("Mock for `test` is not set"))(v)
+                }
+            }
         }
         };
         assert_eq!(actual.to_string(), expected.to_string());
     }
+
+    #[test]
+    fn mock_default_method() {
+        let mut t: ItemTrait = syn::parse2(
+            quote!{
+              trait TestExt {
+                fn default(&self) -> u64;
+              }
+            }
+        ).unwrap();
+        let info = ItemTraitInfo::new(&mut t, None).unwrap();
+        let actual = info.mock_struct().map(|_| ()).unwrap_err();
+        let expected = "Method `default` collides with `Default::default` of `MockTestExt`";
+        assert_eq!(expected, actual.to_string());
+    }
 }
@@ -29,6 +29,42 @@ impl TraitItemMethodInfo {
         }
     }
 
+    /// Generate the field of the mock struct that holds the closure called instead of the method.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// pub merge_sort: Option<Box<dyn Fn(Vec<u8>) -> PromiseOrValue<Vec<u8>>>>,
+    /// ```
+    pub fn mock_field(&self) -> TokenStream2 {
+        let ident = &self.attr_sig_info.ident;
+        let arg_types = self.attr_sig_info.args.iter().map(|arg| &arg.original.ty);
+        let returns = &self.attr_sig_info.returns;
+        quote! {
+            pub #ident: Option<Box<dyn Fn(#(#arg_types),*) #returns>>,
+        }
+    }
+
+    /// Generate the method of the mock struct that forwards all arguments to the closure.
+    ///
+    /// # Example:
+    /// ```ignore
+    /// pub fn merge_sort(&self, arr: Vec<u8>) -> PromiseOrValue<Vec<u8>> {
+    ///     (self.merge_sort.as_ref().expect("Mock for `merge_sort` is not set"))(arr)
+    /// }
+    /// ```
+    pub fn mock_method(&self) -> TokenStream2 {
+        let ident = &self.attr_sig_info.ident;
+        let args = self.attr_sig_info.args.iter().map(|arg| &arg.original);
+        let arg_idents = self.attr_sig_info.args.iter().map(|arg| &arg.ident);
+        let returns = &self.attr_sig_info.returns;
+        let error_msg = format!("Mock for `{}` is not set", ident);
+        quote! {
+            pub fn #ident(&self, #(#args),*) #returns {
+                (self.#ident.as_ref().expect(#error_msg))(#(#arg_idents),*)
+            }
+        }
+    }
+
     pub fn generate_serialier(
         attr_sig_info: &AttrSigInfo,
         serializer: &SerializerType,
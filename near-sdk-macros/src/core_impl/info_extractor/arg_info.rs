@@ -12,6 +12,8 @@ pub enum BindgenArgType {
     CallbackResultArg,
     /// An argument that we read from all `env::promise_result()`.
     CallbackArgVec,
+    /// The raw bytes of the contract state passed to a `#[migrate]` method.
+    OldState,
 }
 
 /// A single argument of a function after it was processed by the bindgen.
@@ -34,6 +36,8 @@ pub struct ArgInfo {
     pub bindgen_ty: BindgenArgType,
     /// Type of serializer that we use for this argument.
     pub serializer_ty: SerializerType,
+    /// Whether the argument is checked with `near_sdk::Validate` before the method is called.
+    pub is_validated: bool,
     /// The original `PatType` of the argument.
     pub original: PatType,
 }
@@ -69,6 +73,7 @@ impl ArgInfo {
         let mut bindgen_ty = BindgenArgType::Regular;
         // In the absence of serialization attributes this is a JSON serialization.
         let mut serializer_ty = SerializerType::JSON;
+        let mut validate_attr = None;
         for attr in &mut original.attrs {
             let attr_str = attr.path.to_token_stream().to_string();
             match attr_str.as_str() {
@@ -85,12 +90,24 @@ impl ArgInfo {
                     let serializer: SerializerAttr = syn::parse2(attr.tokens.clone())?;
                     serializer_ty = serializer.serializer_type;
                 }
+                "validate" => {
+                    validate_attr = Some(attr.span());
+                }
                 _ => {
                     non_bindgen_attrs.push((*attr).clone());
                 }
             }
         }
 
+        if let Some(span) = validate_attr {
+            if !matches!(bindgen_ty, BindgenArgType::Regular) {
+                return Err(Error::new(
+                    span,
+                    "#[validate] is only supported on arguments read from the input",
+                ));
+            }
+        }
+
         original.attrs.retain(|attr| {
             let attr_str = attr.path.to_token_stream().to_string();
             attr_str != "callback"
@@ -98,6 +115,7 @@ impl ArgInfo {
                 && attr_str != "serializer"
                 && attr_str != "callback_result"
                 && attr_str != "callback_unwrap"
+                && attr_str != "validate"
         });
 
         Ok(Self {
@@ -110,6 +128,7 @@ impl ArgInfo {
             ty,
             bindgen_ty,
             serializer_ty,
+            is_validated: validate_attr.is_some(),
             original: original.clone(),
         })
     }
@@ -1,8 +1,11 @@
-use super::{ArgInfo, BindgenArgType, InitAttr, MethodType, SerializerAttr, SerializerType};
+use super::{
+    ArgInfo, BindgenArgType, InitAttr, MethodType, OnlyAttr, SerializerAttr, SerializerType,
+};
+use crate::core_impl::utils;
 use proc_macro2::Span;
 use quote::ToTokens;
 use syn::spanned::Spanned;
-use syn::{Attribute, Error, FnArg, Ident, Receiver, ReturnType, Signature};
+use syn::{Attribute, Error, FnArg, Ident, LitStr, Receiver, ReturnType, Signature};
 
 /// Information extracted from method attributes and signature.
 pub struct AttrSigInfo {
@@ -18,6 +21,8 @@ pub struct AttrSigInfo {
     pub is_payable: bool,
     /// Whether method can accept calls from self (current account)
     pub is_private: bool,
+    /// Roles from `#[only(roles(...))]`, checked with `near_sdk::AssertRole` before the method is called.
+    pub only_roles: Vec<LitStr>,
     /// Whether method returns Result type where only Ok type is serialized
     pub is_handles_result: bool,
     /// The serializer that we use for `env::input()`.
@@ -30,6 +35,8 @@ pub struct AttrSigInfo {
     pub returns: ReturnType,
     /// The original method signature.
     pub original_sig: Signature,
+    /// Documentation of the method collected from its `#[doc]` attributes, if any.
+    pub doc: Option<String>,
 }
 
 impl AttrSigInfo {
@@ -63,15 +70,25 @@ impl AttrSigInfo {
         let mut method_type = MethodType::Regular;
         let mut is_payable = false;
         let mut is_private = false;
+        let mut only_roles = vec![];
         let mut is_handles_result = false;
         // By the default we serialize the result with JSON.
         let mut result_serializer = SerializerType::JSON;
 
+        let mut doc_lines = vec![];
+
         let mut payable_attr = None;
+        let mut only_attr = None;
         for attr in original_attrs.iter() {
             let attr_str = attr.path.to_token_stream().to_string();
             match attr_str.as_str() {
                 "init" => {
+                    if matches!(method_type, MethodType::Migrate) {
+                        return Err(Error::new(
+                            attr.span(),
+                            "Methods can't be marked with both #[init] and #[migrate]",
+                        ));
+                    }
                     let init_attr: InitAttr = syn::parse2(attr.tokens.clone())?;
                     if init_attr.ignore_state {
                         method_type = MethodType::InitIgnoreState;
@@ -79,6 +96,17 @@ impl AttrSigInfo {
                         method_type = MethodType::Init;
                     }
                 }
+                "migrate" => {
+                    if matches!(method_type, MethodType::Init | MethodType::InitIgnoreState) {
+                        return Err(Error::new(
+                            attr.span(),
+                            "Methods can't be marked with both #[init] and #[migrate]",
+                        ));
+                    }
+                    method_type = MethodType::Migrate;
+                    // Only the contract itself can migrate its state.
+                    is_private = true;
+                }
                 "payable" => {
                     payable_attr = Some(attr);
                     is_payable = true;
@@ -86,6 +114,11 @@ impl AttrSigInfo {
                 "private" => {
                     is_private = true;
                 }
+                "only" => {
+                    let only: OnlyAttr = syn::parse2(attr.tokens.clone())?;
+                    only_roles = only.roles;
+                    only_attr = Some(attr);
+                }
                 "result_serializer" => {
                     let serializer: SerializerAttr = syn::parse2(attr.tokens.clone())?;
                     result_serializer = serializer.serializer_type;
@@ -93,6 +126,12 @@ impl AttrSigInfo {
                 "handle_result" => {
                     is_handles_result = true;
                 }
+                "doc" => {
+                    if let Some(line) = utils::doc_attr_text(attr) {
+                        doc_lines.push(line);
+                    }
+                    non_bindgen_attrs.push((*attr).clone());
+                }
                 _ => {
                     non_bindgen_attrs.push((*attr).clone());
                 }
@@ -109,6 +148,32 @@ impl AttrSigInfo {
             }
         }
 
+        if matches!(method_type, MethodType::Migrate) {
+            if receiver.is_some() {
+                return Err(Error::new(
+                    original_sig.span(),
+                    "Migrate methods can't have `self` receiver",
+                ));
+            }
+            match args.as_mut_slice() {
+                [old_state] if old_state_is_bytes(old_state) => {
+                    old_state.bindgen_ty = BindgenArgType::OldState
+                }
+                [old_state] => {
+                    return Err(Error::new(
+                        old_state.ty.span(),
+                        "The old state argument of a migrate method should be `Vec<u8>` or `&[u8]`",
+                    ))
+                }
+                _ => {
+                    return Err(Error::new(
+                        original_sig.inputs.span(),
+                        "Migrate methods should take the old state bytes as their only argument",
+                    ))
+                }
+            }
+        }
+
         if let Some(ref receiver) = receiver {
             if matches!(method_type, MethodType::Regular) {
                 if receiver.mutability.is_none() || receiver.reference.is_none() {
@@ -122,6 +187,21 @@ impl AttrSigInfo {
             }
         };
 
+        if let Some(only_attr) = only_attr {
+            if receiver.is_none() {
+                return Err(Error::new(
+                    only_attr.span(),
+                    "Methods with #[only] attribute must have `self` receiver",
+                ));
+            }
+            if matches!(method_type, MethodType::View) {
+                return Err(Error::new(
+                    only_attr.span(),
+                    "Methods with #[only] attribute must be mutable (not view)",
+                ));
+            }
+        }
+
         if let Some(payable_attr) = payable_attr {
             if matches!(method_type, MethodType::View) {
                 return Err(Error::new(
@@ -133,6 +213,7 @@ impl AttrSigInfo {
 
         *original_attrs = non_bindgen_attrs.clone();
         let returns = original_sig.output.clone();
+        let doc = if doc_lines.is_empty() { None } else { Some(doc_lines.join("\n")) };
 
         let mut result = Self {
             ident,
@@ -142,11 +223,13 @@ impl AttrSigInfo {
             method_type,
             is_payable,
             is_private,
+            only_roles,
             is_handles_result,
             result_serializer,
             receiver,
             returns,
             original_sig: original_sig.clone(),
+            doc,
         };
 
         let input_serializer =
@@ -168,4 +251,20 @@ impl AttrSigInfo {
     pub fn input_args(&self) -> impl Iterator<Item = &ArgInfo> {
         self.args.iter().filter(|arg| matches!(arg.bindgen_ty, BindgenArgType::Regular))
     }
+
+    /// Whether every argument read from `env::input()` is an `Option`, meaning that the method
+    /// can be called without any input at all.
+    pub fn input_args_are_optional(&self) -> bool {
+        self.input_args().all(|arg| utils::type_is_option(&arg.ty))
+    }
+}
+
+/// Whether the migrate argument is `Vec<u8>` or `&[u8]`, the only types the raw state bytes can be
+/// passed as.
+fn old_state_is_bytes(arg: &ArgInfo) -> bool {
+    match (&arg.reference, &arg.mutability) {
+        (None, _) => utils::type_is_byte_vec(&arg.ty),
+        (Some(_), None) => utils::type_is_byte_slice(&arg.ty),
+        (Some(_), Some(_)) => false,
+    }
 }
@@ -1,4 +1,5 @@
 use crate::ImplItemMethodInfo;
+use quote::ToTokens;
 use syn::spanned::Spanned;
 use syn::{Error, ImplItem, ItemImpl, Type};
 
@@ -30,6 +31,72 @@ impl ItemImplInfo {
                 methods.push(method_info);
             }
         }
+
+        // Every exported method becomes a `#[no_mangle]` function, so a name can only be exported
+        // once. Methods under different `#[cfg]` conditions are allowed to share the name.
+        let mut exported: Vec<(&ImplItemMethodInfo, Vec<String>)> = vec![];
+        for method in methods.iter().filter(|m| m.is_public || is_trait_impl) {
+            let ident = &method.attr_signature_info.ident;
+            let cfg_attrs: Vec<String> = method
+                .attr_signature_info
+                .non_bindgen_attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("cfg"))
+                .map(|attr| attr.to_token_stream().to_string())
+                .collect();
+            if let Some((first, _)) = exported.iter().find(|(other, other_cfg_attrs)| {
+                &other.attr_signature_info.ident == ident && other_cfg_attrs == &cfg_attrs
+            }) {
+                let mut err = Error::new(
+                    ident.span(),
+                    format!("Method `{}` is exported more than once.", ident),
+                );
+                err.combine(Error::new(
+                    first.attr_signature_info.ident.span(),
+                    format!("`{}` is first exported here.", ident),
+                ));
+                return Err(err);
+            }
+            exported.push((method, cfg_attrs));
+        }
+
         Ok(Self { is_trait_impl, ty, methods })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn duplicate_exported_method() {
+        let mut item_impl: ItemImpl = parse_quote! {
+            impl Hello {
+                pub fn method(&self) { }
+                pub fn method(&mut self, k: u64) { }
+            }
+        };
+        let err = ItemImplInfo::new(&mut item_impl).err().unwrap();
+        let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec!["Method `method` is exported more than once.", "`method` is first exported here."]
+        );
+    }
+
+    #[test]
+    fn duplicate_method_under_different_cfg() {
+        let mut item_impl: ItemImpl = parse_quote! {
+            impl Hello {
+                #[cfg(feature = "myfeature")]
+                pub fn method(&self) { }
+                #[cfg(not(feature = "myfeature"))]
+                pub fn method(&self) { }
+                fn helper(&self) { }
+                fn helper(&mut self) { }
+            }
+        };
+        assert!(ItemImplInfo::new(&mut item_impl).is_ok());
+    }
+}
@@ -21,6 +21,9 @@ mod item_impl_info;
 mod init_attr;
 pub use init_attr::InitAttr;
 
+mod only_attr;
+pub use only_attr::OnlyAttr;
+
 pub use item_impl_info::ItemImplInfo;
 
 /// Type of serialization we use.
@@ -38,6 +41,7 @@ pub enum MethodType {
     View,
     Init,
     InitIgnoreState,
+    Migrate,
 }
 
 /// Whether the input struct is used for serialization or deserialization.
@@ -0,0 +1,29 @@
+use proc_macro2::Ident;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Error, LitStr, Token};
+
+/// Arguments of the `#[only(roles("role0", "role1"))]` attribute.
+pub struct OnlyAttr {
+    /// Roles, any of which allows calling the method.
+    pub roles: Vec<LitStr>,
+}
+
+impl Parse for OnlyAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let _paren_token = syn::parenthesized!(content in input);
+        let ident: Ident = content.parse()?;
+        if ident != "roles" {
+            return Err(Error::new(ident.span(), "Unsupported only attribute."));
+        }
+        let roles_content;
+        let _roles_paren_token = syn::parenthesized!(roles_content in content);
+        let roles: Punctuated<LitStr, Token![,]> =
+            roles_content.parse_terminated(|input| input.parse())?;
+        if roles.is_empty() {
+            return Err(Error::new(ident.span(), "At least one role should be specified."));
+        }
+        Ok(Self { roles: roles.into_iter().collect() })
+    }
+}
@@ -0,0 +1,139 @@
+use crate::core_impl::utils;
+use crate::{ImplItemMethodInfo, MethodType, SerializerType};
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Error, ReturnType, Type};
+
+impl ImplItemMethodInfo {
+    /// Generates ABI struct for this method. The JSON schemas are generated with `generator`, which
+    /// is expected to be a `schemars::gen::SchemaGenerator` in scope.
+    ///
+    /// # Example:
+    /// The following method:
+    /// ```ignore
+    /// #[payable]
+    /// fn f3(&mut self, arg0: FancyStruct, arg1: u64) -> Result<IsOk, Error> { }
+    /// ```
+    /// will produce this struct:
+    /// ```ignore
+    /// near_sdk::__private::AbiFunction {
+    ///     name: "f3".to_string(),
+    ///     doc: None,
+    ///     kind: near_sdk::__private::AbiFunctionKind::Call,
+    ///     modifiers: vec![near_sdk::__private::AbiFunctionModifier::Payable],
+    ///     params: vec![
+    ///         near_sdk::__private::AbiParameter {
+    ///             name: "arg0".to_string(),
+    ///             type_schema: generator.subschema_for::<FancyStruct>()
+    ///         },
+    ///         near_sdk::__private::AbiParameter {
+    ///             name: "arg1".to_string(),
+    ///             type_schema: generator.subschema_for::<u64>()
+    ///         }
+    ///     ],
+    ///     result: Some(generator.subschema_for::<Result<IsOk, Error> >())
+    /// }
+    /// ```
+    /// Only JSON arguments and results are supported, since the ABI describes them with JSON
+    /// schemas.
+    pub fn abi_struct(&self) -> syn::Result<TokenStream2> {
+        let attr_sig_info = &self.attr_signature_info;
+        let ident = &attr_sig_info.ident;
+        let method_name_str = ident.to_string();
+        if attr_sig_info.input_args().next().is_some()
+            && matches!(attr_sig_info.input_serializer, SerializerType::Borsh)
+        {
+            return Err(Error::new(
+                ident.span(),
+                "ABI generation only supports methods with JSON serialized arguments",
+            ));
+        }
+        let kind = if matches!(attr_sig_info.method_type, MethodType::View) {
+            quote! { near_sdk::__private::AbiFunctionKind::View }
+        } else {
+            quote! { near_sdk::__private::AbiFunctionKind::Call }
+        };
+        let mut modifiers = vec![];
+        if matches!(attr_sig_info.method_type, MethodType::Init | MethodType::InitIgnoreState) {
+            modifiers.push(quote! { near_sdk::__private::AbiFunctionModifier::Init });
+        }
+        if attr_sig_info.is_private {
+            modifiers.push(quote! { near_sdk::__private::AbiFunctionModifier::Private });
+        }
+        if attr_sig_info.is_payable {
+            modifiers.push(quote! { near_sdk::__private::AbiFunctionModifier::Payable });
+        }
+        let params = attr_sig_info.input_args().map(|arg| {
+            let name = arg.ident.to_string();
+            let ty = &arg.ty;
+            quote! {
+                near_sdk::__private::AbiParameter {
+                    name: #name.to_string(),
+                    type_schema: generator.subschema_for::<#ty>()
+                }
+            }
+        });
+        let result = match self.abi_result_type()? {
+            Some(ty) => quote! {
+                Some(generator.subschema_for::<#ty>())
+            },
+            None => quote! {
+                None
+            },
+        };
+        let doc = match &attr_sig_info.doc {
+            Some(doc) => quote! {
+                Some(#doc.to_string())
+            },
+            None => quote! {
+                None
+            },
+        };
+
+        Ok(quote! {
+            near_sdk::__private::AbiFunction {
+                name: #method_name_str.to_string(),
+                doc: #doc,
+                kind: #kind,
+                modifiers: vec![#(#modifiers),*],
+                params: vec![#(#params),*],
+                result: #result
+            }
+        })
+    }
+
+    /// The type of the JSON value returned by the method, if it returns one.
+    fn abi_result_type(&self) -> syn::Result<Option<&Type>> {
+        let attr_sig_info = &self.attr_signature_info;
+        let ty = match &attr_sig_info.returns {
+            ReturnType::Type(_, ty)
+                if matches!(attr_sig_info.method_type, MethodType::View | MethodType::Regular) =>
+            {
+                ty.as_ref()
+            }
+            // Init and migrate methods write the returned state instead of returning it.
+            _ => return Ok(None),
+        };
+        let ty = match utils::extract_ok_type(ty) {
+            Some(ok_type) if attr_sig_info.is_handles_result => ok_type,
+            _ => ty,
+        };
+        // References to the state are serialized as the referenced value.
+        let ty = match ty {
+            Type::Reference(reference) => reference.elem.as_ref(),
+            _ => ty,
+        };
+        if utils::type_is_promise(ty) {
+            return Ok(None);
+        }
+        let ty = utils::extract_promise_or_value_type(ty).unwrap_or(ty);
+        if matches!(attr_sig_info.result_serializer, SerializerType::Borsh) {
+            return Err(Error::new(
+                attr_sig_info.ident.span(),
+                "ABI generation only supports methods with JSON serialized results",
+            ));
+        }
+        Ok(Some(ty))
+    }
+}
@@ -2,7 +2,7 @@ use crate::{BindgenArgType, ImplItemMethodInfo, InputStructType, MethodType, Ser
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::ReturnType;
+use syn::{ReturnType, Type};
 
 impl ImplItemMethodInfo {
     /// Generates metadata struct for this method.
@@ -29,10 +29,12 @@ impl ImplItemMethodInfo {
     ///     },
     ///     callbacks: vec![],
     ///     callbacks_vec: None,
-    ///     result: Some(Result < IsOk, Error > ::schema_container())
+    ///     result: Some(Result < IsOk, Error > ::schema_container()),
+    ///     doc: None
     /// }
     /// ```
     /// If args are serialized with Borsh it will not include `#[derive(borsh::BorshSchema)]`.
+    /// If the method has doc comments, their text is placed into `doc`.
     pub fn metadata_struct(&self) -> TokenStream2 {
         let method_name_str = self.attr_signature_info.ident.to_string();
         let is_view = matches!(&self.attr_signature_info.method_type, &MethodType::View);
@@ -101,11 +103,29 @@ impl ImplItemMethodInfo {
                 }
             }
             ReturnType::Type(_, ty) => {
-                quote! {
-                    Some(#ty::schema_container())
+                // References to the state are serialized as the referenced value. The referenced
+                // type is qualified so that generic types like `Vec<T>` parse in expression position.
+                match ty.as_ref() {
+                    Type::Reference(reference) => {
+                        let ty = &reference.elem;
+                        quote! {
+                            Some(<#ty as near_sdk::borsh::BorshSchema>::schema_container())
+                        }
+                    }
+                    _ => quote! {
+                        Some(#ty::schema_container())
+                    },
                 }
             }
         };
+        let doc = match &self.attr_signature_info.doc {
+            Some(doc) => quote! {
+                Some(#doc.to_string())
+            },
+            None => quote! {
+                None
+            },
+        };
 
         quote! {
              near_sdk::__private::MethodMetadata {
@@ -115,7 +135,8 @@ impl ImplItemMethodInfo {
                  args: #args,
                  callbacks: vec![#(#callbacks),*],
                  callbacks_vec: #callbacks_vec,
-                 result: #result
+                 result: #result,
+                 doc: #doc
              }
         }
     }
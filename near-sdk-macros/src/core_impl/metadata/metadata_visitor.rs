@@ -65,6 +65,31 @@ impl MetadataVisitor {
             }
         })
     }
+
+    /// Generates `__near_abi()`, which returns the ABI of all visited methods. Its JSON is what is
+    /// written to the ABI file.
+    #[cfg(feature = "abi")]
+    pub fn generate_abi_method(&self) -> syn::Result<TokenStream2> {
+        if !self.errors.is_empty() {
+            return Err(self.errors[0].clone());
+        }
+        let functions = self
+            .impl_item_infos
+            .iter()
+            .flat_map(|i| &i.methods)
+            .map(|m| m.abi_struct())
+            .collect::<syn::Result<Vec<TokenStream2>>>()?;
+        Ok(quote! {
+            #[cfg(not(target_arch = "wasm32"))]
+            pub fn __near_abi() -> near_sdk::__private::AbiRoot {
+                let mut generator = near_sdk::__private::schemars::gen::SchemaGenerator::default();
+                let functions = vec![
+                    #(#functions),*
+                ];
+                near_sdk::__private::AbiRoot::new(functions, generator.take_definitions())
+            }
+        })
+    }
 }
 
 #[rustfmt::skip]
@@ -108,7 +133,8 @@ mod tests {
                         args: None,
                         callbacks: vec![],
                         callbacks_vec: None,
-                        result: None
+                        result: None,
+                        doc: None
                     },
                     near_sdk::__private::MethodMetadata {
                         name: "f2".to_string(),
@@ -127,7 +153,8 @@ mod tests {
                         },
                         callbacks: vec![],
                         callbacks_vec: None,
-                        result: None
+                        result: None,
+                        doc: None
                     },
                     near_sdk::__private::MethodMetadata {
                         name: "f3".to_string(),
@@ -146,7 +173,104 @@ mod tests {
                         },
                         callbacks: vec![],
                         callbacks_vec: None,
-                        result: Some(Result < IsOk, Error > ::schema_container())
+                        result: Some(Result < IsOk, Error > ::schema_container()),
+                        doc: None
+                    }
+                ]);
+                let data = near_sdk::borsh::BorshSerialize::try_to_vec(&metadata)
+                    .expect("Failed to serialize the metadata using Borsh");
+                near_sdk::env::value_return(&data);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn documented_method() {
+        let code = quote! {
+            #[near_bindgen]
+            impl Hello {
+                /// Returns the greeting.
+                ///
+                /// Does not modify the state.
+                pub fn greeting(&self) { }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+
+        let mut visitor = MetadataVisitor::new();
+        visitor.visit_file(&file);
+
+        let actual = visitor.generate_metadata_method().unwrap();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn metadata() {
+                near_sdk::env::setup_panic_hook();
+                use borsh::*;
+                let metadata = near_sdk::__private::Metadata::new(vec![
+                    near_sdk::__private::MethodMetadata {
+                        name: "greeting".to_string(),
+                        is_view: true,
+                        is_init: false,
+                        args: None,
+                        callbacks: vec![],
+                        callbacks_vec: None,
+                        result: None,
+                        doc: Some("Returns the greeting.\n\nDoes not modify the state.".to_string())
+                    }
+                ]);
+                let data = near_sdk::borsh::BorshSerialize::try_to_vec(&metadata)
+                    .expect("Failed to serialize the metadata using Borsh");
+                near_sdk::env::value_return(&data);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn reference_result() {
+        let code = quote! {
+            #[near_bindgen]
+            impl Hello {
+                pub fn value(&self) -> &u64 { }
+                pub fn values(&self) -> &Vec<u64> { }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+
+        let mut visitor = MetadataVisitor::new();
+        visitor.visit_file(&file);
+
+        let actual = visitor.generate_metadata_method().unwrap();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            pub extern "C" fn metadata() {
+                near_sdk::env::setup_panic_hook();
+                use borsh::*;
+                let metadata = near_sdk::__private::Metadata::new(vec![
+                    near_sdk::__private::MethodMetadata {
+                        name: "value".to_string(),
+                        is_view: true,
+                        is_init: false,
+                        args: None,
+                        callbacks: vec![],
+                        callbacks_vec: None,
+                        result: Some(<u64 as near_sdk::borsh::BorshSchema>::schema_container()),
+                        doc: None
+                    },
+                    near_sdk::__private::MethodMetadata {
+                        name: "values".to_string(),
+                        is_view: true,
+                        is_init: false,
+                        args: None,
+                        callbacks: vec![],
+                        callbacks_vec: None,
+                        result: Some(<Vec<u64> as near_sdk::borsh::BorshSchema>::schema_container()),
+                        doc: None
                     }
                 ]);
                 let data = near_sdk::borsh::BorshSerialize::try_to_vec(&metadata)
@@ -156,4 +280,163 @@ mod tests {
         );
         assert_eq!(expected.to_string(), actual.to_string());
     }
+
+    #[cfg(feature = "abi")]
+    #[test]
+    fn abi_two_methods() {
+        let code = quote! {
+            #[near_bindgen]
+            impl Hello {
+                /// Returns the greeting.
+                pub fn get_greeting(&self, account_id: AccountId) -> Option<String> { }
+                #[payable]
+                pub fn set_greeting(&mut self, message: String, times: u64) { }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+
+        let mut visitor = MetadataVisitor::new();
+        visitor.visit_file(&file);
+
+        let actual = visitor.generate_abi_method().unwrap();
+        let expected = quote!(
+            #[cfg(not(target_arch = "wasm32"))]
+            pub fn __near_abi() -> near_sdk::__private::AbiRoot {
+                let mut generator = near_sdk::__private::schemars::gen::SchemaGenerator::default();
+                let functions = vec![
+                    near_sdk::__private::AbiFunction {
+                        name: "get_greeting".to_string(),
+                        doc: Some("Returns the greeting.".to_string()),
+                        kind: near_sdk::__private::AbiFunctionKind::View,
+                        modifiers: vec![],
+                        params: vec![
+                            near_sdk::__private::AbiParameter {
+                                name: "account_id".to_string(),
+                                type_schema: generator.subschema_for::<AccountId>()
+                            }
+                        ],
+                        result: Some(generator.subschema_for::<Option<String> >())
+                    },
+                    near_sdk::__private::AbiFunction {
+                        name: "set_greeting".to_string(),
+                        doc: None,
+                        kind: near_sdk::__private::AbiFunctionKind::Call,
+                        modifiers: vec![near_sdk::__private::AbiFunctionModifier::Payable],
+                        params: vec![
+                            near_sdk::__private::AbiParameter {
+                                name: "message".to_string(),
+                                type_schema: generator.subschema_for::<String>()
+                            },
+                            near_sdk::__private::AbiParameter {
+                                name: "times".to_string(),
+                                type_schema: generator.subschema_for::<u64>()
+                            }
+                        ],
+                        result: None
+                    }
+                ];
+                near_sdk::__private::AbiRoot::new(functions, generator.take_definitions())
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[cfg(feature = "abi")]
+    #[test]
+    fn abi_result_types() {
+        let code = quote! {
+            #[near_bindgen]
+            impl Hello {
+                #[init]
+                #[private]
+                pub fn new() -> Self { }
+                #[handle_result]
+                pub fn checked(&self) -> Result<&u64, Error> { }
+                pub fn value(&self) -> &Vec<u64> { }
+                pub fn forward(&mut self) -> PromiseOrValue<U128> { }
+                pub fn call_other(&mut self) -> Promise { }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+
+        let mut visitor = MetadataVisitor::new();
+        visitor.visit_file(&file);
+
+        let actual = visitor.generate_abi_method().unwrap();
+        let expected = quote!(
+            #[cfg(not(target_arch = "wasm32"))]
+            pub fn __near_abi() -> near_sdk::__private::AbiRoot {
+                let mut generator = near_sdk::__private::schemars::gen::SchemaGenerator::default();
+                let functions = vec![
+                    near_sdk::__private::AbiFunction {
+                        name: "new".to_string(),
+                        doc: None,
+                        kind: near_sdk::__private::AbiFunctionKind::Call,
+                        modifiers: vec![
+                            near_sdk::__private::AbiFunctionModifier::Init,
+                            near_sdk::__private::AbiFunctionModifier::Private
+                        ],
+                        params: vec![],
+                        result: None
+                    },
+                    near_sdk::__private::AbiFunction {
+                        name: "checked".to_string(),
+                        doc: None,
+                        kind: near_sdk::__private::AbiFunctionKind::View,
+                        modifiers: vec![],
+                        params: vec![],
+                        result: Some(generator.subschema_for::<u64>())
+                    },
+                    near_sdk::__private::AbiFunction {
+                        name: "value".to_string(),
+                        doc: None,
+                        kind: near_sdk::__private::AbiFunctionKind::View,
+                        modifiers: vec![],
+                        params: vec![],
+                        result: Some(generator.subschema_for::<Vec<u64> >())
+                    },
+                    near_sdk::__private::AbiFunction {
+                        name: "forward".to_string(),
+                        doc: None,
+                        kind: near_sdk::__private::AbiFunctionKind::Call,
+                        modifiers: vec![],
+                        params: vec![],
+                        result: Some(generator.subschema_for::<U128>())
+                    },
+                    near_sdk::__private::AbiFunction {
+                        name: "call_other".to_string(),
+                        doc: None,
+                        kind: near_sdk::__private::AbiFunctionKind::Call,
+                        modifiers: vec![],
+                        params: vec![],
+                        result: None
+                    }
+                ];
+                near_sdk::__private::AbiRoot::new(functions, generator.take_definitions())
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[cfg(feature = "abi")]
+    #[test]
+    fn abi_borsh_args() {
+        let code = quote! {
+            #[near_bindgen]
+            impl Hello {
+                pub fn f(&self, #[serializer(borsh)] arg: u64) { }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+
+        let mut visitor = MetadataVisitor::new();
+        visitor.visit_file(&file);
+
+        let actual = visitor.generate_abi_method().unwrap_err();
+        let expected = "ABI generation only supports methods with JSON serialized arguments";
+        assert_eq!(expected, actual.to_string());
+    }
 }
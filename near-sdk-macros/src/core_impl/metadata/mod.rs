@@ -1,2 +1,4 @@
+#[cfg(feature = "abi")]
+pub mod abi_generator;
 pub mod metadata_generator;
 pub mod metadata_visitor;
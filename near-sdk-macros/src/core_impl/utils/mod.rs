@@ -1,4 +1,4 @@
-use syn::{GenericArgument, Path, PathArguments, Type};
+use syn::{Attribute, GenericArgument, Lit, Meta, MetaNameValue, Path, PathArguments, Type};
 
 /// Checks whether the given path is literally "Result".
 /// Note that it won't match a fully qualified name `core::result::Result` or a type alias like
@@ -17,6 +17,123 @@ pub(crate) fn type_is_result(ty: &Type) -> bool {
     }
 }
 
+/// Checks whether the given type is literally `Option<T>`.
+/// Like `type_is_result`, it won't match fully qualified names or type aliases.
+pub(crate) fn type_is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let path = &type_path.path;
+            path.leading_colon.is_none()
+                && path.segments.len() == 1
+                && path.segments.iter().next().unwrap().ident == "Option"
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether the given type is literally `PromiseOrValue<T>` or `near_sdk::PromiseOrValue<T>`.
+/// Like `type_is_result`, it won't match other paths or type aliases.
+pub(crate) fn type_is_promise_or_value(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let path = &type_path.path;
+            let idents: Vec<_> = path.segments.iter().map(|segment| &segment.ident).collect();
+            path.leading_colon.is_none()
+                && match idents.as_slice() {
+                    [ident] => *ident == "PromiseOrValue",
+                    [krate, ident] => *krate == "near_sdk" && *ident == "PromiseOrValue",
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether the given type is literally `Promise` or `near_sdk::Promise`.
+/// Like `type_is_result`, it won't match other paths or type aliases.
+#[cfg(feature = "abi")]
+pub(crate) fn type_is_promise(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let path = &type_path.path;
+            let idents: Vec<_> = path.segments.iter().map(|segment| &segment.ident).collect();
+            path.leading_colon.is_none()
+                && match idents.as_slice() {
+                    [ident] => *ident == "Promise",
+                    [krate, ident] => *krate == "near_sdk" && *ident == "Promise",
+                    _ => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+/// Extracts the value type from a `PromiseOrValue` type.
+///
+/// For example, given `PromiseOrValue<U128>` type it will return `U128` type.
+#[cfg(feature = "abi")]
+pub(crate) fn extract_promise_or_value_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(type_path) if type_is_promise_or_value(ty) => {
+            let type_params = &type_path.path.segments.last()?.arguments;
+            let generic_arg = match type_params {
+                PathArguments::AngleBracketed(params) => Some(params.args.first()?),
+                _ => None,
+            }?;
+            match generic_arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Checks whether the given type is `u8`.
+fn type_is_u8(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.is_ident("u8"),
+        _ => false,
+    }
+}
+
+/// Checks whether the given type is `Vec<u8>`, `std::vec::Vec<u8>` or `alloc::vec::Vec<u8>`.
+/// Like `type_is_result`, it won't match type aliases.
+pub(crate) fn type_is_byte_vec(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let path = &type_path.path;
+            let idents: Vec<_> = path.segments.iter().map(|segment| &segment.ident).collect();
+            let is_vec = match idents.as_slice() {
+                [ident] => path.leading_colon.is_none() && *ident == "Vec",
+                [krate, module, ident] => {
+                    (*krate == "std" || *krate == "alloc") && *module == "vec" && *ident == "Vec"
+                }
+                _ => false,
+            };
+            let item_ty = match &path.segments.last().unwrap().arguments {
+                PathArguments::AngleBracketed(params) if params.args.len() == 1 => {
+                    match params.args.first() {
+                        Some(GenericArgument::Type(ty)) => Some(ty),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+            is_vec && item_ty.map(type_is_u8).unwrap_or(false)
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether the given type is the slice `[u8]`.
+pub(crate) fn type_is_byte_slice(ty: &Type) -> bool {
+    match ty {
+        Type::Slice(slice) => type_is_u8(&slice.elem),
+        _ => false,
+    }
+}
+
 /// Extracts the Ok type from a `Result` type.
 ///
 /// For example, given `Result<String, u8>` type it will return `String` type.
@@ -39,3 +156,17 @@ pub(crate) fn extract_ok_type(ty: &Type) -> Option<&Type> {
         _ => None,
     }
 }
+
+/// Extracts the text of a `#[doc = "..."]` attribute.
+///
+/// Doc comments written as `/// text` are desugared into `#[doc = " text"]`, so the single leading
+/// space is stripped.
+pub(crate) fn doc_attr_text(attr: &Attribute) -> Option<String> {
+    match attr.parse_meta().ok()? {
+        Meta::NameValue(MetaNameValue { lit: Lit::Str(lit), .. }) => {
+            let text = lit.value();
+            Some(text.strip_prefix(' ').map(str::to_string).unwrap_or(text))
+        }
+        _ => None,
+    }
+}
@@ -27,6 +27,18 @@ use syn::{File, ItemEnum, ItemImpl, ItemStruct, ItemTrait};
 /// done through `serde` serialized as JSON, but this can be overwritten using
 /// `#[result_serializer(borsh)]`.
 ///
+/// Methods marked with `#[only(roles("admin", "owner"))]` call
+/// `near_sdk::AssertRole::assert_role(&self, &["admin", "owner"])` before the method body runs. The
+/// contract has to implement `near_sdk::AssertRole` and panic when the predecessor has none of the
+/// listed roles. Such methods can't be view methods, since view calls have no predecessor.
+///
+/// Arguments marked with `#[validate]` are checked with `near_sdk::Validate` after they are
+/// deserialized, and the method panics with the returned error if any of them is invalid.
+///
+/// A `#[migrate]` method takes the raw bytes of the current state as its only argument, either as
+/// `Vec<u8>` or `&[u8]`, and returns the new state, which is then written. Such methods are private
+/// and don't load the typed state. With `#[handle_result]` the method may return `Result<Self, E>`.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -86,6 +98,13 @@ pub fn near_bindgen(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// Each of these static methods takes positional arguments defined by the Trait,
 /// then the receiver_id, the attached deposit and the amount of gas and returns a new Promise.
 ///
+/// In `#[cfg(test)]` builds, the module also contains a `Mock{Trait}` struct (e.g. `MockCalculator`)
+/// with an optional closure field per trait method. Calling the method on the mock calls the closure.
+/// The mock only holds these closures: the functions above don't go through it and still create
+/// promises. Being `#[cfg(test)]`, it is only visible to unit tests of the crate declaring the trait,
+/// not to `tests/` or near-sdk-sim. A trait method named `default` is rejected, since it collides
+/// with the mock's `Default` implementation.
+///
 /// # Examples
 ///
 /// ```ignore
@@ -167,6 +186,11 @@ pub fn init(_attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 /// `metadata` generates the metadata method and should be placed at the very end of the `lib.rs` file.
+///
+/// With the `abi` feature of near-sdk it also generates `__near_abi()`, which returns the JSON ABI
+/// of the contract methods in the near-abi format. The ABI file can be written from a unit test,
+/// e.g. `std::fs::write("abi.json", __near_abi().to_json())`. Only methods with JSON serialized
+/// arguments and results are supported.
 // TODO: Once Rust allows inner attributes and custom procedural macros for modules we should switch this
 // to be `#![metadata]` attribute at the top of the contract file instead. https://github.com/rust-lang/rust/issues/54727
 #[proc_macro]
@@ -178,6 +202,14 @@ pub fn metadata(item: TokenStream) -> TokenStream {
             Ok(x) => x,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
+        #[cfg(feature = "abi")]
+        let generated = match visitor.generate_abi_method() {
+            Ok(abi) => quote! {
+                #generated
+                #abi
+            },
+            Err(err) => return TokenStream::from(err.to_compile_error()),
+        };
         TokenStream::from(quote! {
             #input
             #generated
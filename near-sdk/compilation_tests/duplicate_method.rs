@@ -0,0 +1,17 @@
+//! Two exported methods with the same name are not allowed.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Test {}
+
+#[near_bindgen]
+impl Test {
+    pub fn get(&self) {}
+
+    pub fn get(&mut self) {}
+}
+
+fn main() {}
@@ -739,6 +739,11 @@ pub fn state_read<T: borsh::BorshDeserialize>() -> Option<T> {
         .map(|data| T::try_from_slice(&data).expect("Cannot deserialize the contract state."))
 }
 
+/// Reads the contract state without deserializing it, e.g. to migrate it from an older layout.
+pub fn state_read_raw() -> Option<Vec<u8>> {
+    storage_read(STATE_KEY)
+}
+
 pub fn state_write<T: borsh::BorshSerialize>(state: &T) {
     let data = state.try_to_vec().expect("Cannot serialize the contract state.");
     storage_write(STATE_KEY, &data);
@@ -975,4 +980,17 @@ mod tests {
             .build());
         assert_eq!(super::signer_account_pk(), key);
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn state_read_raw() {
+        crate::test_utils::test_env::setup();
+        assert_eq!(super::state_read_raw(), None);
+
+        super::state_write(&(5u64, String::from("state")));
+        assert_eq!(
+            super::state_read_raw(),
+            Some((5u64, String::from("state")).try_to_vec().unwrap())
+        );
+    }
 }
@@ -9,6 +9,17 @@ use std::convert::TryFrom;
 )]
 pub struct Base58CryptoHash(CryptoHash);
 
+#[cfg(feature = "abi")]
+impl schemars::JsonSchema for Base58CryptoHash {
+    fn schema_name() -> String {
+        "Base58CryptoHash".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
 impl From<Base58CryptoHash> for CryptoHash {
     fn from(v: Base58CryptoHash) -> CryptoHash {
         v.0
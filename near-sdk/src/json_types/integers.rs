@@ -47,6 +47,17 @@ macro_rules! impl_str_type {
                 ))
             }
         }
+
+        #[cfg(feature = "abi")]
+        impl schemars::JsonSchema for $iden {
+            fn schema_name() -> String {
+                stringify!($iden).to_string()
+            }
+
+            fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                String::json_schema(gen)
+            }
+        }
     };
 }
 
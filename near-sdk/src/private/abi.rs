@@ -0,0 +1,69 @@
+use schemars::schema::Schema;
+use schemars::Map;
+use serde::Serialize;
+
+/// Version of the ABI format.
+const ABI_SCHEMA_SEMVER: &str = "0.1.0";
+
+/// ABI of the contract, following the near-abi JSON format.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct AbiRoot {
+    /// Semver of the ABI format.
+    pub schema_version: String,
+    /// ABI of all methods.
+    pub functions: Vec<AbiFunction>,
+    /// Definitions of the types referenced by the parameter and result schemas.
+    pub definitions: Map<String, Schema>,
+}
+
+impl AbiRoot {
+    pub fn new(functions: Vec<AbiFunction>, definitions: Map<String, Schema>) -> Self {
+        Self { schema_version: ABI_SCHEMA_SEMVER.to_string(), functions, definitions }
+    }
+
+    /// Serializes the ABI into the JSON that is written to the ABI file.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to serialize the ABI using JSON")
+    }
+}
+
+/// ABI of a single method.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct AbiFunction {
+    pub name: String,
+    /// Documentation of the method taken from its doc comments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+    /// Whether method does not modify the state.
+    pub kind: AbiFunctionKind,
+    /// Attributes that change how the method can be called.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub modifiers: Vec<AbiFunctionModifier>,
+    /// Arguments read from the JSON input of the method.
+    pub params: Vec<AbiParameter>,
+    /// Schema of the JSON return value, if the method returns one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Schema>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AbiFunctionKind {
+    View,
+    Call,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AbiFunctionModifier {
+    Init,
+    Private,
+    Payable,
+}
+
+/// A single argument of a method.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct AbiParameter {
+    pub name: String,
+    pub type_schema: Schema,
+}
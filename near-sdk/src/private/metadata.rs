@@ -1,6 +1,6 @@
 use borsh::{schema::BorshSchemaContainer, BorshDeserialize, BorshSchema, BorshSerialize};
 /// Version of the metadata format.
-const METADATA_SEMVER: [u32; 3] = [0, 1, 0];
+const METADATA_SEMVER: [u32; 3] = [0, 2, 0];
 
 /// Metadata of the contract.
 #[derive(BorshSerialize, BorshDeserialize, BorshSchema, Debug, PartialEq)]
@@ -33,4 +33,6 @@ pub struct MethodMetadata {
     pub callbacks_vec: Option<BorshSchemaContainer>,
     /// Schema of the return type.
     pub result: Option<BorshSchemaContainer>,
+    /// Documentation of the method taken from its doc comments.
+    pub doc: Option<String>,
 }
@@ -2,6 +2,15 @@ mod metadata;
 
 pub use metadata::{Metadata, MethodMetadata};
 
+#[cfg(feature = "abi")]
+mod abi;
+
+#[cfg(feature = "abi")]
+pub use abi::{AbiFunction, AbiFunctionKind, AbiFunctionModifier, AbiParameter, AbiRoot};
+
+#[cfg(feature = "abi")]
+pub use schemars;
+
 use crate::IntoStorageKey;
 use borsh::BorshSerialize;
 
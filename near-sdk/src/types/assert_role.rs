@@ -0,0 +1,23 @@
+/// Checks the roles of the predecessor account. Contracts with `#[near_bindgen]` methods marked
+/// with `#[only(roles(...))]` have to implement this trait, and the method panics before its body
+/// runs unless the predecessor has one of the listed roles.
+///
+/// ```
+/// use near_sdk::{env, AccountId, AssertRole};
+///
+/// struct Contract {
+///     owner_id: AccountId,
+/// }
+///
+/// impl AssertRole for Contract {
+///     fn assert_role(&self, roles: &[&str]) {
+///         if !(roles.contains(&"owner") && env::predecessor_account_id() == self.owner_id) {
+///             env::panic_str("The predecessor doesn't have any of the required roles");
+///         }
+///     }
+/// }
+/// ```
+pub trait AssertRole {
+    /// Panics unless the predecessor account has at least one of `roles`.
+    fn assert_role(&self, roles: &[&str]);
+}
@@ -17,6 +17,12 @@ mod error;
 pub use self::error::Abort;
 pub use self::error::FunctionError;
 
+mod validate;
+pub use self::validate::Validate;
+
+mod assert_role;
+pub use self::assert_role::AssertRole;
+
 /// Raw type for duration in nanoseconds
 pub type Duration = u64;
 
@@ -0,0 +1,41 @@
+/// Checks a method argument after it was deserialized. Arguments marked with `#[validate]` in
+/// `#[near_bindgen]` methods have to implement this trait, and the method panics with the returned
+/// error before its body runs if the argument is invalid.
+///
+/// ```
+/// use near_sdk::Validate;
+///
+/// struct Percentage(u8);
+///
+/// impl Validate for Percentage {
+///     type Error = String;
+///
+///     fn validate(&self) -> Result<(), Self::Error> {
+///         if self.0 > 100 {
+///             return Err(format!("{} is above 100", self.0));
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// assert!(Percentage(42).validate().is_ok());
+/// assert!(Percentage(101).validate().is_err());
+/// ```
+pub trait Validate {
+    /// Error describing why the value is invalid.
+    type Error: core::fmt::Display;
+
+    fn validate(&self) -> Result<(), Self::Error>;
+}
+
+/// Missing optional arguments are always valid.
+impl<T: Validate> Validate for Option<T> {
+    type Error = T::Error;
+
+    fn validate(&self) -> Result<(), Self::Error> {
+        match self {
+            Some(value) => value.validate(),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "abi")]
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::{metadata, near_bindgen, AccountId};
+
+use std::collections::HashMap;
+
+metadata! {
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+pub struct Balances {
+    balances: HashMap<AccountId, u128>,
+}
+
+#[near_bindgen]
+impl Balances {
+    /// Sets the balance of the account.
+    pub fn set_balance(&mut self, account_id: AccountId, balance: U128) {
+        self.balances.insert(account_id, balance.0);
+    }
+
+    pub fn get_balance(&self, account_id: AccountId) -> Option<U128> {
+        self.balances.get(&account_id).map(|balance| U128(*balance))
+    }
+}
+}
+
+#[test]
+fn abi_contains_both_methods() {
+    let abi: serde_json::Value = serde_json::from_str(&__near_abi().to_json()).unwrap();
+    assert_eq!(abi["schema_version"], "0.1.0");
+
+    let functions = abi["functions"].as_array().unwrap();
+    assert_eq!(functions.len(), 2);
+
+    let set_balance = &functions[0];
+    assert_eq!(set_balance["name"], "set_balance");
+    assert_eq!(set_balance["doc"], "Sets the balance of the account.");
+    assert_eq!(set_balance["kind"], "call");
+    assert_eq!(set_balance["params"][0]["name"], "account_id");
+    assert_eq!(set_balance["params"][0]["type_schema"]["$ref"], "#/definitions/AccountId");
+    assert_eq!(set_balance["params"][1]["name"], "balance");
+    assert_eq!(set_balance["params"][1]["type_schema"]["$ref"], "#/definitions/U128");
+    assert!(set_balance.get("result").is_none());
+
+    let get_balance = &functions[1];
+    assert_eq!(get_balance["name"], "get_balance");
+    assert_eq!(get_balance["kind"], "view");
+    assert_eq!(get_balance["params"][0]["name"], "account_id");
+    assert_eq!(get_balance["params"][0]["type_schema"]["$ref"], "#/definitions/AccountId");
+    assert!(get_balance["result"]["anyOf"].is_array());
+
+    assert_eq!(abi["definitions"]["AccountId"]["type"], "string");
+    assert_eq!(abi["definitions"]["U128"]["type"], "string");
+}